@@ -78,6 +78,9 @@ void DB::initMember(nlohmann::json &member)
 	if (!member.count("vProto")) member["vProto"] = -1;
 	if (!member.count("remoteTraceTarget")) member["remoteTraceTarget"] = nlohmann::json();
 	if (!member.count("removeTraceLevel")) member["remoteTraceLevel"] = 0;
+	if (!member.count("name")) member["name"] = "";
+	if (!member.count("description")) member["description"] = "";
+	if ((!member.count("metadata"))||(!member["metadata"].is_object())) member["metadata"] = nlohmann::json::object();
 	member["objtype"] = "member";
 }
 
@@ -138,6 +138,11 @@ public:
 
 	virtual AuthInfo getSSOAuthInfo(const nlohmann::json &member, const std::string &redirectURL) { return AuthInfo(); }
 
+	/**
+	 * @return True if this store persists member name, description, and metadata
+	 */
+	virtual bool persistsMemberMetadata() const { return true; }
+
 	inline void addListener(DB::ChangeListener *const listener)
 	{
 		std::lock_guard<std::mutex> l(_changeListeners_l);
@@ -53,6 +53,8 @@ public:
 
 	AuthInfo getSSOAuthInfo(const nlohmann::json &member, const std::string &redirectURL);
 
+	bool persistsMemberMetadata() const;
+
 	inline void addDB(const std::shared_ptr<DB> &db)
 	{
 		db->addListener(this);
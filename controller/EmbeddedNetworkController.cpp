@@ -59,6 +59,12 @@ using json = nlohmann::json;
 // Global maximum size of arrays in JSON objects
 #define ZT_CONTROLLER_MAX_ARRAY_SIZE 16384
 
+// Maximum length of member name and description strings
+#define ZT_CONTROLLER_MAX_MEMBER_STRING_LENGTH 1024
+
+// Maximum size of serialized member metadata JSON
+#define ZT_CONTROLLER_MAX_MEMBER_METADATA_SIZE 16384
+
 namespace ZeroTier {
 
 namespace {
@@ -553,6 +559,148 @@ void EmbeddedNetworkController::request(
 	_queue.post(qe);
 }
 
+unsigned int EmbeddedNetworkController::_updateMember(
+	json &b,
+	json &member,
+	const int64_t now,
+	std::string &responseBody,
+	std::string &responseContentType)
+{
+	try {
+		if (b.count("activeBridge")) member["activeBridge"] = OSUtils::jsonBool(b["activeBridge"], false);
+		if (b.count("noAutoAssignIps")) member["noAutoAssignIps"] = OSUtils::jsonBool(b["noAutoAssignIps"], false);
+		if (b.count("authenticationExpiryTime")) member["authenticationExpiryTime"] = (uint64_t)OSUtils::jsonInt(b["authenticationExpiryTime"], 0ULL);
+		if (b.count("authenticationURL")) member["authenticationURL"] = OSUtils::jsonString(b["authenticationURL"], "");
+		if ((b.count("name"))||(b.count("description"))||(b.count("metadata"))) {
+			json md(member["metadata"]),name(member["name"]),description(member["description"]);
+			if (b.count("name")) {
+				name = b["name"];
+				if (name.is_null()) {
+					name = "";
+				} else if ((!name.is_string())||(name.get<std::string>().length() > ZT_CONTROLLER_MAX_MEMBER_STRING_LENGTH)) {
+					responseBody = "{ \"message\": \"name must be a string of at most 1024 bytes or null\" }";
+					responseContentType = "application/json";
+					return 400;
+				}
+			}
+			if (b.count("description")) {
+				description = b["description"];
+				if (description.is_null()) {
+					description = "";
+				} else if ((!description.is_string())||(description.get<std::string>().length() > ZT_CONTROLLER_MAX_MEMBER_STRING_LENGTH)) {
+					responseBody = "{ \"message\": \"description must be a string of at most 1024 bytes or null\" }";
+					responseContentType = "application/json";
+					return 400;
+				}
+			}
+			if (b.count("metadata")) {
+				md = b["metadata"];
+				if (md.is_null()) {
+					md = json::object();
+				} else if ((!md.is_object())||(OSUtils::jsonDump(md,-1).length() > ZT_CONTROLLER_MAX_MEMBER_METADATA_SIZE)) {
+					responseBody = "{ \"message\": \"metadata must be a JSON object of at most 16384 bytes or null\" }";
+					responseContentType = "application/json";
+					return 400;
+				}
+			}
+
+			// Stores that don't persist these fields only accept their current values back, so
+			// clients that POST a member object they previously fetched keep working.
+			if ((!_db.persistsMemberMetadata())&&((name != member["name"])||(description != member["description"])||(md != member["metadata"]))) {
+				responseBody = "{ \"message\": \"member name, description, and metadata are not supported by this controller's database\" }";
+				responseContentType = "application/json";
+				return 400;
+			}
+
+			member["name"] = name;
+			member["description"] = description;
+			member["metadata"] = md;
+		}
+
+		if (b.count("remoteTraceTarget")) {
+			const std::string rtt(OSUtils::jsonString(b["remoteTraceTarget"],""));
+			if (rtt.length() == 10) {
+				member["remoteTraceTarget"] = rtt;
+			} else {
+				member["remoteTraceTarget"] = json();
+			}
+		}
+		if (b.count("remoteTraceLevel")) member["remoteTraceLevel"] = OSUtils::jsonInt(b["remoteTraceLevel"],0ULL);
+
+		if (b.count("authorized")) {
+			const bool newAuth = OSUtils::jsonBool(b["authorized"],false);
+			if (newAuth != OSUtils::jsonBool(member["authorized"],false)) {
+				member["authorized"] = newAuth;
+				member[((newAuth) ? "lastAuthorizedTime" : "lastDeauthorizedTime")] = now;
+				if (newAuth) {
+					member["lastAuthorizedCredentialType"] = "api";
+					member["lastAuthorizedCredential"] = json();
+				}
+			}
+		}
+
+		if (b.count("ipAssignments")) {
+			json &ipa = b["ipAssignments"];
+			if (ipa.is_array()) {
+				json mipa(json::array());
+				for(unsigned long i=0;i<ipa.size();++i) {
+					std::string ips = ipa[i];
+					InetAddress ip(ips.c_str());
+					if ((ip.ss_family == AF_INET)||(ip.ss_family == AF_INET6)) {
+						char tmpip[64];
+						mipa.push_back(ip.toIpString(tmpip));
+						if (mipa.size() >= ZT_CONTROLLER_MAX_ARRAY_SIZE)
+							break;
+					}
+				}
+				member["ipAssignments"] = mipa;
+			}
+		}
+
+		if (b.count("tags")) {
+			json &tags = b["tags"];
+			if (tags.is_array()) {
+				std::map<uint64_t,uint64_t> mtags;
+				for(unsigned long i=0;i<tags.size();++i) {
+					json &tag = tags[i];
+					if ((tag.is_array())&&(tag.size() == 2))
+						mtags[OSUtils::jsonInt(tag[0],0ULL) & 0xffffffffULL] = OSUtils::jsonInt(tag[1],0ULL) & 0xffffffffULL;
+				}
+				json mtagsa = json::array();
+				for(std::map<uint64_t,uint64_t>::iterator t(mtags.begin());t!=mtags.end();++t) {
+					json ta = json::array();
+					ta.push_back(t->first);
+					ta.push_back(t->second);
+					mtagsa.push_back(ta);
+					if (mtagsa.size() >= ZT_CONTROLLER_MAX_ARRAY_SIZE)
+						break;
+				}
+				member["tags"] = mtagsa;
+			}
+		}
+
+		if (b.count("capabilities")) {
+			json &capabilities = b["capabilities"];
+			if (capabilities.is_array()) {
+				json mcaps = json::array();
+				for(unsigned long i=0;i<capabilities.size();++i) {
+					mcaps.push_back(OSUtils::jsonInt(capabilities[i],0ULL));
+					if (mcaps.size() >= ZT_CONTROLLER_MAX_ARRAY_SIZE)
+						break;
+				}
+				std::sort(mcaps.begin(),mcaps.end());
+				mcaps.erase(std::unique(mcaps.begin(),mcaps.end()),mcaps.end());
+				member["capabilities"] = mcaps;
+			}
+		}
+	} catch ( ... ) {
+		responseBody = "{ \"message\": \"exception while processing parameters in JSON body\" }";
+		responseContentType = "application/json";
+		return 400;
+	}
+	return 200;
+}
+
 unsigned int EmbeddedNetworkController::handleControlPlaneHttpGET(
 	const std::vector<std::string> &path,
 	const std::map<std::string,std::string> &urlArgs,
@@ -604,6 +752,22 @@ unsigned int EmbeddedNetworkController::handleControlPlaneHttpGET(
 					}
 					return 200;
 
+				} else if ((path.size() == 3)&&(path[2] == "export")) {
+					// Export all members in the format accepted by POST .../import
+
+					json out = json::array();
+					std::vector<json> members;
+					if (_db.get(nwid,network,members)) {
+						for(auto member=members.begin();member!=members.end();++member)
+							out.push_back(*member);
+					}
+					json exp = json::object();
+					exp["id"] = network["id"];
+					exp["members"] = out;
+					responseBody = OSUtils::jsonDump(exp);
+					responseContentType = "application/json";
+					return 200;
+
 				} // else 404
 
 			} else {
@@ -694,93 +858,9 @@ unsigned int EmbeddedNetworkController::handleControlPlaneHttpPOST(
 					_db.get(nwid,network,address,member);
 					DB::initMember(member);
 
-					try {
-						if (b.count("activeBridge")) member["activeBridge"] = OSUtils::jsonBool(b["activeBridge"], false);
-						if (b.count("noAutoAssignIps")) member["noAutoAssignIps"] = OSUtils::jsonBool(b["noAutoAssignIps"], false);
-						if (b.count("authenticationExpiryTime")) member["authenticationExpiryTime"] = (uint64_t)OSUtils::jsonInt(b["authenticationExpiryTime"], 0ULL);
-						if (b.count("authenticationURL")) member["authenticationURL"] = OSUtils::jsonString(b["authenticationURL"], "");
-
-						if (b.count("remoteTraceTarget")) {
-							const std::string rtt(OSUtils::jsonString(b["remoteTraceTarget"],""));
-							if (rtt.length() == 10) {
-								member["remoteTraceTarget"] = rtt;
-							} else {
-								member["remoteTraceTarget"] = json();
-							}
-						}
-						if (b.count("remoteTraceLevel")) member["remoteTraceLevel"] = OSUtils::jsonInt(b["remoteTraceLevel"],0ULL);
-
-						if (b.count("authorized")) {
-							const bool newAuth = OSUtils::jsonBool(b["authorized"],false);
-							if (newAuth != OSUtils::jsonBool(member["authorized"],false)) {
-								member["authorized"] = newAuth;
-								member[((newAuth) ? "lastAuthorizedTime" : "lastDeauthorizedTime")] = now;
-								if (newAuth) {
-									member["lastAuthorizedCredentialType"] = "api";
-									member["lastAuthorizedCredential"] = json();
-								}
-							}
-						}
-
-						if (b.count("ipAssignments")) {
-							json &ipa = b["ipAssignments"];
-							if (ipa.is_array()) {
-								json mipa(json::array());
-								for(unsigned long i=0;i<ipa.size();++i) {
-									std::string ips = ipa[i];
-									InetAddress ip(ips.c_str());
-									if ((ip.ss_family == AF_INET)||(ip.ss_family == AF_INET6)) {
-										char tmpip[64];
-										mipa.push_back(ip.toIpString(tmpip));
-										if (mipa.size() >= ZT_CONTROLLER_MAX_ARRAY_SIZE)
-											break;
-									}
-								}
-								member["ipAssignments"] = mipa;
-							}
-						}
-
-						if (b.count("tags")) {
-							json &tags = b["tags"];
-							if (tags.is_array()) {
-								std::map<uint64_t,uint64_t> mtags;
-								for(unsigned long i=0;i<tags.size();++i) {
-									json &tag = tags[i];
-									if ((tag.is_array())&&(tag.size() == 2))
-										mtags[OSUtils::jsonInt(tag[0],0ULL) & 0xffffffffULL] = OSUtils::jsonInt(tag[1],0ULL) & 0xffffffffULL;
-								}
-								json mtagsa = json::array();
-								for(std::map<uint64_t,uint64_t>::iterator t(mtags.begin());t!=mtags.end();++t) {
-									json ta = json::array();
-									ta.push_back(t->first);
-									ta.push_back(t->second);
-									mtagsa.push_back(ta);
-									if (mtagsa.size() >= ZT_CONTROLLER_MAX_ARRAY_SIZE)
-										break;
-								}
-								member["tags"] = mtagsa;
-							}
-						}
-
-						if (b.count("capabilities")) {
-							json &capabilities = b["capabilities"];
-							if (capabilities.is_array()) {
-								json mcaps = json::array();
-								for(unsigned long i=0;i<capabilities.size();++i) {
-									mcaps.push_back(OSUtils::jsonInt(capabilities[i],0ULL));
-									if (mcaps.size() >= ZT_CONTROLLER_MAX_ARRAY_SIZE)
-										break;
-								}
-								std::sort(mcaps.begin(),mcaps.end());
-								mcaps.erase(std::unique(mcaps.begin(),mcaps.end()),mcaps.end());
-								member["capabilities"] = mcaps;
-							}
-						}
-					} catch ( ... ) {
-						responseBody = "{ \"message\": \"exception while processing parameters in JSON body\" }";
-						responseContentType = "application/json";
-						return 400;
-					}
+					const unsigned int rc = _updateMember(b,member,now,responseBody,responseContentType);
+					if (rc != 200)
+						return rc;
 
 					member["id"] = addrs;
 					member["address"] = addrs; // legacy
@@ -791,6 +871,57 @@ unsigned int EmbeddedNetworkController::handleControlPlaneHttpPOST(
 					responseBody = OSUtils::jsonDump(member);
 					responseContentType = "application/json";
 
+					return 200;
+				} else if ((path.size() == 3)&&(path[2] == "import")) {
+					json network;
+					if (!_db.get(nwid,network))
+						return 404;
+
+					json &in = b["members"];
+					if (!in.is_array()) {
+						responseBody = "{ \"message\": \"members must be an array\" }";
+						responseContentType = "application/json";
+						return 400;
+					}
+
+					// Validate every entry before saving any so a bad import leaves the network untouched
+					std::vector<json> members;
+					members.reserve(in.size());
+					for(unsigned long i=0;i<in.size();++i) {
+						json &m = in[i];
+						const std::string id(m.is_object() ? OSUtils::jsonString(m["id"],"") : std::string());
+						if ((id.length() != 10)||(Utils::hexStrToU64(id.c_str()) == 0)) {
+							responseBody = "{ \"message\": \"each member must be an object with a 10-digit hex id\" }";
+							responseContentType = "application/json";
+							return 400;
+						}
+						const uint64_t address = Utils::hexStrToU64(id.c_str());
+						char addrs[24];
+						OSUtils::ztsnprintf(addrs,sizeof(addrs),"%.10llx",(unsigned long long)address);
+
+						json member;
+						_db.get(nwid,network,address,member);
+						DB::initMember(member);
+
+						const unsigned int rc = _updateMember(m,member,now,responseBody,responseContentType);
+						if (rc != 200)
+							return rc;
+
+						member["id"] = addrs;
+						member["address"] = addrs; // legacy
+						member["nwid"] = nwids;
+						DB::cleanMember(member);
+						members.push_back(member);
+					}
+
+					for(auto m=members.begin();m!=members.end();++m)
+						_db.save(*m,true);
+
+					char tmp[64];
+					OSUtils::ztsnprintf(tmp,sizeof(tmp),"{ \"imported\": %lu }",(unsigned long)members.size());
+					responseBody = tmp;
+					responseContentType = "application/json";
+
 					return 200;
 				} // else 404
 
@@ -97,6 +97,7 @@ public:
 private:
 	void _request(uint64_t nwid,const InetAddress &fromAddr,uint64_t requestPacketId,const Identity &identity,const Dictionary<ZT_NETWORKCONFIG_METADATA_DICT_CAPACITY> &metaData);
 	void _startThreads();
+	unsigned int _updateMember(nlohmann::json &b,nlohmann::json &member,const int64_t now,std::string &responseBody,std::string &responseContentType);
 
 	struct _RQEntry
 	{
@@ -103,6 +103,7 @@ public:
 
 	virtual bool waitForReady();
 	virtual bool isReady();
+	virtual bool persistsMemberMetadata() const { return false; }
 	virtual bool save(nlohmann::json &record,bool notifyListeners);
 	virtual void eraseNetwork(const uint64_t networkId);
 	virtual void eraseMember(const uint64_t networkId, const uint64_t memberId);
@@ -327,6 +327,10 @@ extern "C" {
 #define ZT_REMOTE_TRACE_FIELD__CREDENTIAL_REVOCATION_TARGET "credRevocationTarget"
 #define ZT_REMOTE_TRACE_FIELD__REASON "reason"
 #define ZT_REMOTE_TRACE_FIELD__NETWORK_CONTROLLER_ID "networkControllerId"
+#define ZT_REMOTE_TRACE_FIELD__WORLD_ID "worldId"
+#define ZT_REMOTE_TRACE_FIELD__WORLD_TIMESTAMP "worldTs"
+#define ZT_REMOTE_TRACE_FIELD__WORLD_FINGERPRINT "worldFingerprint"
+#define ZT_REMOTE_TRACE_FIELD__CONFLICTING_WORLD_FINGERPRINT "conflictingWorldFingerprint"
 
 // Event types in remote traces
 #define ZT_REMOTE_TRACE_EVENT__RESETTING_PATHS_IN_SCOPE 0x1000
@@ -336,6 +340,7 @@ extern "C" {
 #define ZT_REMOTE_TRACE_EVENT__PACKET_MAC_FAILURE 0x1004
 #define ZT_REMOTE_TRACE_EVENT__PACKET_INVALID 0x1005
 #define ZT_REMOTE_TRACE_EVENT__DROPPED_HELLO 0x1006
+#define ZT_REMOTE_TRACE_EVENT__WORLD_CONFLICT 0x1007
 #define ZT_REMOTE_TRACE_EVENT__OUTGOING_NETWORK_FRAME_DROPPED 0x2000
 #define ZT_REMOTE_TRACE_EVENT__INCOMING_NETWORK_ACCESS_DENIED 0x2001
 #define ZT_REMOTE_TRACE_EVENT__INCOMING_NETWORK_FRAME_DROPPED 0x2002
@@ -352,6 +357,7 @@ extern "C" {
 #define ZT_REMOTE_TRACE_EVENT__PACKET_MAC_FAILURE_S "1004"
 #define ZT_REMOTE_TRACE_EVENT__PACKET_INVALID_S "1005"
 #define ZT_REMOTE_TRACE_EVENT__DROPPED_HELLO_S "1006"
+#define ZT_REMOTE_TRACE_EVENT__WORLD_CONFLICT_S "1007"
 #define ZT_REMOTE_TRACE_EVENT__OUTGOING_NETWORK_FRAME_DROPPED_S "2000"
 #define ZT_REMOTE_TRACE_EVENT__INCOMING_NETWORK_ACCESS_DENIED_S "2001"
 #define ZT_REMOTE_TRACE_EVENT__INCOMING_NETWORK_FRAME_DROPPED_S "2002"
@@ -623,6 +629,16 @@ typedef struct
 	int online;
 } ZT_NodeStatus;
 
+/**
+ * Number of buckets in the received packet size histogram in ZT_NodeStatistics
+ */
+#define ZT_NODE_STATISTICS_PACKET_SIZE_BUCKETS 16
+
+/**
+ * Width in bytes of each received packet size histogram bucket
+ */
+#define ZT_NODE_STATISTICS_PACKET_SIZE_BUCKET_WIDTH 128
+
 /**
  * Internal node statistics
  *
@@ -639,6 +655,15 @@ typedef struct
 	 * Number of bytes for each protocol verb received
 	 */
 	uint64_t inVerbBytes[32];
+
+	/**
+	 * Histogram of received packet sizes (after reassembly)
+	 *
+	 * Bucket N counts packets of N*ZT_NODE_STATISTICS_PACKET_SIZE_BUCKET_WIDTH
+	 * bytes up to but not including (N+1)*ZT_NODE_STATISTICS_PACKET_SIZE_BUCKET_WIDTH
+	 * bytes. The last bucket also counts all larger packets.
+	 */
+	uint64_t inPacketSizeCounts[ZT_NODE_STATISTICS_PACKET_SIZE_BUCKETS];
 } ZT_NodeStatistics;
 
 /**
@@ -1974,6 +1999,16 @@ ZT_SDK_API uint64_t ZT_Node_address(ZT_Node *node);
  */
 ZT_SDK_API void ZT_Node_status(ZT_Node *node,ZT_NodeStatus *status);
 
+/**
+ * Get received packet statistics for this node
+ *
+ * Counters are updated without locking and are approximate.
+ *
+ * @param node Node instance
+ * @param stats Buffer to fill with current statistics
+ */
+ZT_SDK_API void ZT_Node_statistics(ZT_Node *node,ZT_NodeStatistics *stats);
+
 /**
  * Get a list of known peer nodes
  *
@@ -2044,6 +2079,24 @@ ZT_SDK_API int ZT_Node_addLocalInterfaceAddress(ZT_Node *node,const struct socka
  */
 ZT_SDK_API void ZT_Node_clearLocalInterfaceAddresses(ZT_Node *node);
 
+/**
+ * Reset physical paths to peers
+ *
+ * Matching paths are re-contacted and will not be used again until the
+ * remote end responds on them. This can be called after events that are
+ * known to break paths, such as a VPN coming up or a captive portal login,
+ * instead of waiting for them to time out. Current paths can be listed
+ * with ZT_Node_peers().
+ *
+ * @param node Node instance
+ * @param tptr Thread pointer to pass to functions/callbacks resulting from this call
+ * @param now Current clock in milliseconds
+ * @param localSocket Local socket to match or -1 for any
+ * @param remoteAddress Remote physical address to match or NULL for any
+ * @return Number of paths reset
+ */
+ZT_SDK_API unsigned int ZT_Node_resetPaths(ZT_Node *node,void *tptr,int64_t now,int64_t localSocket,const struct sockaddr_storage *remoteAddress);
+
 /**
  * Send a VERB_USER_MESSAGE to another ZeroTier node
  *
@@ -543,7 +543,11 @@ bool IncomingPacket::_doOK(const RuntimeEnvironment *RR,void *tPtr,const SharedP
 					while (ptr < endOfWorlds) {
 						World w;
 						ptr += w.deserialize(*this,ptr);
-						RR->topology->addWorld(tPtr,w,false);
+						if (!RR->topology->addWorld(tPtr,w,false)) {
+							World current;
+							if (RR->topology->worldConflicts(w,current))
+								RR->t->worldConflict(tPtr,peer->address(),current,w);
+						}
 					}
 				} else {
 					ptr += worldsLen;
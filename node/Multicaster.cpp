@@ -406,6 +406,18 @@ void Multicaster::clean(int64_t now)
 	}
 }
 
+void Multicaster::removeNetwork(uint64_t nwid)
+{
+	Mutex::Lock _l(_groups_m);
+	Multicaster::Key *k = (Multicaster::Key *)0;
+	MulticastGroupStatus *s = (MulticastGroupStatus *)0;
+	Hashtable<Multicaster::Key,MulticastGroupStatus>::Iterator mm(_groups);
+	while (mm.next(k,s)) {
+		if (k->nwid == nwid)
+			_groups.erase(*k);
+	}
+}
+
 void Multicaster::_add(void *tPtr,int64_t now,uint64_t nwid,const MulticastGroup &mg,MulticastGroupStatus &gs,const Address &member)
 {
 	// assumes _groups_m is locked
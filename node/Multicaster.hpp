@@ -145,6 +145,13 @@ public:
 	 */
 	void clean(int64_t now);
 
+	/**
+	 * Drop all groups, members, and pending sends for a network
+	 *
+	 * @param nwid Network ID
+	 */
+	void removeNetwork(uint64_t nwid);
+
 private:
 	struct Key
 	{
@@ -417,6 +417,8 @@ ZT_ResultCode Node::leave(uint64_t nwid,void **uptr,void *tptr)
 		nUserPtr = (*nw)->userPtr();
 	}
 
+	RR->mc->removeNetwork(nwid);
+
 	if (nUserPtr)
 		RR->node->configureVirtualNetworkPort(tptr,nwid,nUserPtr,ZT_VIRTUAL_NETWORK_CONFIG_OPERATION_DESTROY,&ctmp);
 
@@ -475,6 +477,11 @@ void Node::status(ZT_NodeStatus *status) const
 	status->online = _online ? 1 : 0;
 }
 
+void Node::statistics(ZT_NodeStatistics *stats) const
+{
+	memcpy(stats,(const void *)&_stats,sizeof(ZT_NodeStatistics));
+}
+
 ZT_PeerList *Node::peers() const
 {
 	std::vector< std::pair< Address,SharedPtr<Peer> > > peers(RR->topology->allPeers());
@@ -601,6 +608,36 @@ void Node::clearLocalInterfaceAddresses()
 	_directPaths.clear();
 }
 
+// Closure used to reset paths matching a local socket and/or remote address
+class _ResetPaths
+{
+public:
+	_ResetPaths(void *tPtr,int64_t now,int64_t localSocket,const InetAddress &remoteAddress) :
+		count(0),
+		_now(now),
+		_tPtr(tPtr),
+		_localSocket(localSocket),
+		_remoteAddress(remoteAddress) {}
+
+	inline void operator()(Topology &t,const SharedPtr<Peer> &p) { count += p->resetPaths(_tPtr,_localSocket,_remoteAddress,_now); }
+
+	unsigned int count;
+
+private:
+	int64_t _now;
+	void *_tPtr;
+	int64_t _localSocket;
+	InetAddress _remoteAddress;
+};
+
+unsigned int Node::resetPaths(void *tptr,int64_t now,int64_t localSocket,const struct sockaddr_storage *remoteAddress)
+{
+	_now = now;
+	_ResetPaths rp(tptr,now,localSocket,(remoteAddress) ? *(reinterpret_cast<const InetAddress *>(remoteAddress)) : InetAddress());
+	RR->topology->eachPeer<_ResetPaths &>(rp);
+	return rp.count;
+}
+
 int Node::sendUserMessage(void *tptr,uint64_t dest,uint64_t typeId,const void *data,unsigned int len)
 {
 	try {
@@ -960,6 +997,13 @@ void ZT_Node_status(ZT_Node *node,ZT_NodeStatus *status)
 	} catch ( ... ) {}
 }
 
+void ZT_Node_statistics(ZT_Node *node,ZT_NodeStatistics *stats)
+{
+	try {
+		reinterpret_cast<ZeroTier::Node *>(node)->statistics(stats);
+	} catch ( ... ) {}
+}
+
 ZT_PeerList *ZT_Node_peers(ZT_Node *node)
 {
 	try {
@@ -1010,6 +1054,15 @@ void ZT_Node_clearLocalInterfaceAddresses(ZT_Node *node)
 	} catch ( ... ) {}
 }
 
+unsigned int ZT_Node_resetPaths(ZT_Node *node,void *tptr,int64_t now,int64_t localSocket,const struct sockaddr_storage *remoteAddress)
+{
+	try {
+		return reinterpret_cast<ZeroTier::Node *>(node)->resetPaths(tptr,now,localSocket,remoteAddress);
+	} catch ( ... ) {
+		return 0;
+	}
+}
+
 int ZT_Node_sendUserMessage(ZT_Node *node,void *tptr,uint64_t dest,uint64_t typeId,const void *data,unsigned int len)
 {
 	try {
@@ -92,12 +92,14 @@ public:
 	ZT_ResultCode deorbit(void *tptr,uint64_t moonWorldId);
 	uint64_t address() const;
 	void status(ZT_NodeStatus *status) const;
+	void statistics(ZT_NodeStatistics *stats) const;
 	ZT_PeerList *peers() const;
 	ZT_VirtualNetworkConfig *networkConfig(uint64_t nwid) const;
 	ZT_VirtualNetworkList *networks() const;
 	void freeQueryResult(void *qr);
 	int addLocalInterfaceAddress(const struct sockaddr_storage *addr);
 	void clearLocalInterfaceAddresses();
+	unsigned int resetPaths(void *tptr,int64_t now,int64_t localSocket,const struct sockaddr_storage *remoteAddress);
 	int sendUserMessage(void *tptr,uint64_t dest,uint64_t typeId,const void *data,unsigned int len);
 	void setNetconfMaster(void *networkControllerInstance);
 
@@ -267,6 +269,7 @@ public:
 	{
 		++_stats.inVerbCounts[v];
 		_stats.inVerbBytes[v] += (uint64_t)bytes;
+		++_stats.inPacketSizeCounts[std::min(bytes / ZT_NODE_STATISTICS_PACKET_SIZE_BUCKET_WIDTH,(unsigned int)(ZT_NODE_STATISTICS_PACKET_SIZE_BUCKETS - 1))];
 	}
 
 	inline void setLowBandwidthMode(bool isEnabled)
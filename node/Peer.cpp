@@ -625,6 +625,23 @@ void Peer::resetWithinScope(void *tPtr,InetAddress::IpScope scope,int inetAddres
 	}
 }
 
+unsigned int Peer::resetPaths(void *tPtr,int64_t localSocket,const InetAddress &remoteAddress,int64_t now)
+{
+	unsigned int count = 0;
+	Mutex::Lock _l(_paths_m);
+	for(unsigned int i=0;i<ZT_MAX_PEER_NETWORK_PATHS;++i) {
+		if (_paths[i].p) {
+			if (((localSocket == -1)||(_paths[i].p->localSocket() == localSocket))&&((!remoteAddress)||(_paths[i].p->address() == remoteAddress))) {
+				attemptToContactAt(tPtr,_paths[i].p->localSocket(),_paths[i].p->address(),now,false);
+				_paths[i].p->sent(now);
+				_paths[i].lr = 0; // path will not be used unless it speaks again
+				++count;
+			}
+		} else break;
+	}
+	return count;
+}
+
 void Peer::recordOutgoingPacket(const SharedPtr<Path> &path, const uint64_t packetId,
 	uint16_t payloadLength, const Packet::Verb verb, const int32_t flowId, int64_t now)
 {
@@ -272,6 +272,20 @@ public:
 	 */
 	void resetWithinScope(void *tPtr,InetAddress::IpScope scope,int inetAddressFamily,int64_t now);
 
+	/**
+	 * Reset paths matching a local socket and/or remote address
+	 *
+	 * Matching paths are re-contacted and treated as expired in the same way
+	 * as resetWithinScope(), so they are only used again if they speak.
+	 *
+	 * @param tPtr Thread pointer to be handed through to any callbacks called as a result of this call
+	 * @param localSocket Local socket to match or -1 for any
+	 * @param remoteAddress Remote address to match or nil InetAddress for any
+	 * @param now Current time
+	 * @return Number of paths reset
+	 */
+	unsigned int resetPaths(void *tPtr,int64_t localSocket,const InetAddress &remoteAddress,int64_t now);
+
 	/**
 	 * @param now Current time
 	 * @return All known paths to this peer
@@ -277,6 +277,25 @@ bool Topology::addWorld(void *tPtr,const World &newWorld,bool alwaysAcceptNew)
 	return true;
 }
 
+bool Topology::worldConflicts(const World &w,World &current) const
+{
+	Mutex::Lock _l(_upstreams_m);
+	if (w.type() == World::TYPE_PLANET) {
+		if (_planet.conflictsWith(w)) {
+			current = _planet;
+			return true;
+		}
+	} else if (w.type() == World::TYPE_MOON) {
+		for(std::vector< World >::const_iterator m(_moons.begin());m!=_moons.end();++m) {
+			if (m->conflictsWith(w)) {
+				current = *m;
+				return true;
+			}
+		}
+	}
+	return false;
+}
+
 void Topology::addMoon(void *tPtr,const uint64_t id,const Address &seed)
 {
 	char tmp[ZT_WORLD_MAX_SERIALIZED_LENGTH];
@@ -250,6 +250,15 @@ public:
 	 */
 	bool addWorld(void *tPtr,const World &newWorld,bool alwaysAcceptNew);
 
+	/**
+	 * Check whether a world conflicts with the planet or a moon we already have
+	 *
+	 * @param w World to check
+	 * @param current Set to our current version of this world if a conflict is found
+	 * @return True if w conflicts with our current version (see World::conflictsWith())
+	 */
+	bool worldConflicts(const World &w,World &current) const;
+
 	/**
 	 * Add a moon
 	 *
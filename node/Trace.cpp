@@ -27,6 +27,8 @@
 #include "Tag.hpp"
 #include "Capability.hpp"
 #include "Revocation.hpp"
+#include "World.hpp"
+#include "SHA512.hpp"
 #include "../include/ZeroTierDebug.h"
 
 namespace ZeroTier {
@@ -46,6 +48,34 @@ static void ZT_LOCAL_TRACE(void *const tPtr,const RuntimeEnvironment *const RR,c
 #define ZT_LOCAL_TRACE(...)
 #endif
 
+void Trace::worldConflict(void *const tPtr,const Address &reporter,const World &current,const World &conflicting)
+{
+	char currentFp[(ZT_SHA384_DIGEST_SIZE * 2) + 1],conflictingFp[(ZT_SHA384_DIGEST_SIZE * 2) + 1];
+	uint8_t h[ZT_SHA384_DIGEST_SIZE];
+	Buffer<ZT_WORLD_MAX_SERIALIZED_LENGTH> tmp;
+	current.serialize(tmp,false);
+	SHA384(h,tmp.data(),tmp.size());
+	Utils::hex(h,ZT_SHA384_DIGEST_SIZE,currentFp);
+	tmp.clear();
+	conflicting.serialize(tmp,false);
+	SHA384(h,tmp.data(),tmp.size());
+	Utils::hex(h,ZT_SHA384_DIGEST_SIZE,conflictingFp);
+
+	ZT_LOCAL_TRACE(tPtr,RR,"CONFLICTING world %.16llx timestamp %llu from %.10llx (current %s, conflicting %s)",(unsigned long long)current.id(),(unsigned long long)current.timestamp(),reporter.toInt(),currentFp,conflictingFp);
+
+	Dictionary<ZT_MAX_REMOTE_TRACE_SIZE> d;
+	d.add(ZT_REMOTE_TRACE_FIELD__EVENT,ZT_REMOTE_TRACE_EVENT__WORLD_CONFLICT_S);
+	d.add(ZT_REMOTE_TRACE_FIELD__REMOTE_ZTADDR,reporter);
+	d.add(ZT_REMOTE_TRACE_FIELD__WORLD_ID,current.id());
+	d.add(ZT_REMOTE_TRACE_FIELD__WORLD_TIMESTAMP,(uint64_t)current.timestamp());
+	d.add(ZT_REMOTE_TRACE_FIELD__WORLD_FINGERPRINT,currentFp);
+	d.add(ZT_REMOTE_TRACE_FIELD__CONFLICTING_WORLD_FINGERPRINT,conflictingFp);
+
+	if (_globalTarget)
+		_send(tPtr,d,_globalTarget);
+	_spamToAllNetworks(tPtr,d,Trace::LEVEL_NORMAL);
+}
+
 void Trace::resettingPathsInScope(void *const tPtr,const Address &reporter,const InetAddress &reporterPhysicalAddress,const InetAddress &myPhysicalAddress,const InetAddress::IpScope scope)
 {
 	char tmp[128];
@@ -38,6 +38,7 @@ class Identity;
 class Peer;
 class Path;
 class Network;
+class World;
 class NetworkConfig;
 class MAC;
 class CertificateOfMembership;
@@ -105,6 +106,8 @@ public:
 	{
 	}
 
+	void worldConflict(void *const tPtr,const Address &reporter,const World &current,const World &conflicting);
+
 	void resettingPathsInScope(void *const tPtr,const Address &reporter,const InetAddress &reporterPhysicalAddress,const InetAddress &myPhysicalAddress,const InetAddress::IpScope scope);
 
 	void peerConfirmingUnknownPath(void *const tPtr,const uint64_t networkId,Peer &peer,const SharedPtr<Path> &path,const uint64_t packetId,const Packet::Verb verb);
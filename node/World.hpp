@@ -158,6 +158,29 @@ public:
 		return false;
 	}
 
+	/**
+	 * Check whether an update conflicts with this World
+	 *
+	 * A conflict is a properly signed World with the same ID, type, and timestamp
+	 * but different contents. Since neither can replace the other this indicates
+	 * either a compromised signing key or an operator error, and it should be
+	 * reported rather than silently ignored.
+	 *
+	 * @param update Candidate update
+	 * @return True if update conflicts with this World
+	 */
+	inline bool conflictsWith(const World &update) const
+	{
+		if ((_id == 0)||(_type == TYPE_NULL))
+			return false;
+		if ((_id == update._id)&&(_ts == update._ts)&&(_type == update._type)&&(*this != update)) {
+			Buffer<ZT_WORLD_MAX_SERIALIZED_LENGTH> tmp;
+			update.serialize(tmp,true);
+			return C25519::verify(_updatesMustBeSignedBy,tmp.data(),tmp.size(),update._signature);
+		}
+		return false;
+	}
+
 	/**
 	 * @return True if this World is non-empty
 	 */
@@ -39,6 +39,7 @@
 #include "node/C25519.hpp"
 #include "node/Poly1305.hpp"
 #include "node/CertificateOfMembership.hpp"
+#include "node/World.hpp"
 #include "node/Node.hpp"
 #include "node/IncomingPacket.hpp"
 
@@ -635,6 +636,25 @@ static int testCertificate()
 		return -1;
 	}
 
+	std::cout << "[certificate] Detect conflicting world with same timestamp... "; std::cout.flush();
+	{
+		const C25519::Pair wk(C25519::generate());
+		std::vector<World::Root> roots1,roots2;
+		roots1.push_back(World::Root());
+		roots1.back().identity = idA;
+		roots2.push_back(World::Root());
+		roots2.back().identity = idB;
+		const World w1(World::make(World::TYPE_MOON,0x1234567890ULL,1000,wk.pub,roots1,wk));
+		const World w2(World::make(World::TYPE_MOON,0x1234567890ULL,1000,wk.pub,roots2,wk));
+		const World w3(World::make(World::TYPE_MOON,0x1234567890ULL,1001,wk.pub,roots2,wk));
+		const World forged(World::make(World::TYPE_MOON,0x1234567890ULL,1000,wk.pub,roots2,C25519::generate()));
+		if ((!w1.conflictsWith(w2))||(w1.conflictsWith(w1))||(w1.conflictsWith(w3))||(w1.conflictsWith(forged))) {
+			std::cout << "FAIL" << std::endl;
+			return -1;
+		}
+	}
+	std::cout << "PASS" << std::endl;
+
 	return 0;
 }
 
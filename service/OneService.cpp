@@ -1516,6 +1516,27 @@ public:
 					Mutex::Lock lc(_localConfig_m);
 					res = _localConfig;
 					scode = 200;
+				} else if (ps[0] == "stats") {
+					ZT_NodeStatistics stats;
+					_node->statistics(&stats);
+
+					json &verbs = res["inVerbs"];
+					verbs = json::array();
+					for(unsigned int v=0;v<32;++v) {
+						if (stats.inVerbCounts[v]) {
+							json vj;
+							vj["verb"] = v;
+							vj["count"] = stats.inVerbCounts[v];
+							vj["bytes"] = stats.inVerbBytes[v];
+							verbs.push_back(vj);
+						}
+					}
+					res["inPacketSizeBucketWidth"] = ZT_NODE_STATISTICS_PACKET_SIZE_BUCKET_WIDTH;
+					json &sizes = res["inPacketSizeCounts"];
+					sizes = json::array();
+					for(unsigned int i=0;i<ZT_NODE_STATISTICS_PACKET_SIZE_BUCKETS;++i)
+						sizes.push_back(stats.inPacketSizeCounts[i]);
+					scode = 200;
 				} else if (ps[0] == "status") {
 					ZT_NodeStatus status;
 					_node->status(&status);